@@ -1,6 +1,7 @@
 /// This program contains a list of valid AST nodes that can be constructed and also evaluates an AST to compute a value
-// Standard lib
-use std::error;
+
+// Internal modules
+use super::error::EvaluationError;
 
 // List of allowed AST nodes that can be constructed by the Parser
 // Tokens can be arithmetic operators or a Number
@@ -15,12 +16,81 @@ pub enum Node {
     Multiply(Box<Node>, Box<Node>),
     Divide(Box<Node>, Box<Node>),
     Caret(Box<Node>, Box<Node>),
+    Modulo(Box<Node>, Box<Node>),
+    FloorDivide(Box<Node>, Box<Node>),
     Negative(Box<Node>),
     Number(f64),
+
+    // Comparisons evaluate to `1.0` (true) or `0.0` (false)
+    Equal(Box<Node>, Box<Node>),
+    NotEqual(Box<Node>, Box<Node>),
+    LessThan(Box<Node>, Box<Node>),
+    LessThanEqual(Box<Node>, Box<Node>),
+    GreaterThan(Box<Node>, Box<Node>),
+    GreaterThanEqual(Box<Node>, Box<Node>),
+
+    // A named constant, already resolved to its numeric value (see `resolve_constant`)
+    Constant(f64),
+    // A named unary function call, e.g. `Function("sqrt", ...)` for `sqrt(x)`
+    Function(String, Box<Node>),
+    Absolute(Box<Node>),
+}
+
+// Resolve a named constant identifier (e.g. `pi`, `e`, `tau`) to its numeric value.
+pub fn resolve_constant(name: &str) -> Option<f64> {
+    match name {
+        "pi" => Some(std::f64::consts::PI),
+        "e" => Some(std::f64::consts::E),
+        "tau" => Some(std::f64::consts::TAU),
+        _ => None,
+    }
+}
+
+// Apply a named unary math function, used by both the tree-walking `eval` and the
+// bytecode `exec`. Returns a descriptive error for an unknown name or a domain error.
+fn apply_function(name: &str, x: f64) -> Result<f64, EvaluationError> {
+    match name {
+        "sin" => Ok(x.sin()),
+        "cos" => Ok(x.cos()),
+        "tan" => Ok(x.tan()),
+        "sqrt" => {
+            if x < 0.0 {
+                return Err(EvaluationError::DomainError(format!(
+                    "sqrt of negative number {}",
+                    x
+                )));
+            }
+            Ok(x.sqrt())
+        }
+        "ln" => {
+            if x <= 0.0 {
+                return Err(EvaluationError::DomainError(format!(
+                    "ln of non-positive number {}",
+                    x
+                )));
+            }
+            Ok(x.ln())
+        }
+        "log10" => {
+            if x <= 0.0 {
+                return Err(EvaluationError::DomainError(format!(
+                    "log10 of non-positive number {}",
+                    x
+                )));
+            }
+            Ok(x.log10())
+        }
+        // "abs" is deliberately absent: `Absolute` is the one canonical path for it
+        // (see `Node::Absolute`), so an identifier-based `abs(...)` call falls through
+        // to the `other` arm below rather than duplicating the same logic here.
+        "floor" => Ok(x.floor()),
+        "ceil" => Ok(x.ceil()),
+        other => Err(EvaluationError::UnknownFunction(other.to_string())),
+    }
 }
 
 // Given an AST, calculate the numeric value.
-pub fn eval(expr: Node) -> Result<f64, Box<dyn error::Error>> {
+pub fn eval(expr: Node) -> Result<f64, EvaluationError> {
     use self::Node::*;
     match expr {
         Number(i) => Ok(i),
@@ -30,12 +100,37 @@ pub fn eval(expr: Node) -> Result<f64, Box<dyn error::Error>> {
         Divide(expr1, expr2) => {
             let divisor = eval(*expr2)?;
             if divisor == 0.0 {
-                return Err("Division by zero".into());
+                return Err(EvaluationError::DivisionByZero);
             }
             Ok(eval(*expr1)? / divisor)
         }
         Caret(expr1, expr2) => Ok(eval(*expr1)?.powf(eval(*expr2)?)),
+        Modulo(expr1, expr2) => {
+            let divisor = eval(*expr2)?;
+            if divisor == 0.0 {
+                return Err(EvaluationError::DivisionByZero);
+            }
+            Ok(eval(*expr1)?.rem_euclid(divisor))
+        }
+        FloorDivide(expr1, expr2) => {
+            let divisor = eval(*expr2)?;
+            if divisor == 0.0 {
+                return Err(EvaluationError::DivisionByZero);
+            }
+            Ok((eval(*expr1)? / divisor).floor())
+        }
         Negative(expr) => Ok(-eval(*expr)?),
+        Equal(expr1, expr2) => Ok(if eval(*expr1)? == eval(*expr2)? { 1.0 } else { 0.0 }),
+        NotEqual(expr1, expr2) => Ok(if eval(*expr1)? != eval(*expr2)? { 1.0 } else { 0.0 }),
+        LessThan(expr1, expr2) => Ok(if eval(*expr1)? < eval(*expr2)? { 1.0 } else { 0.0 }),
+        LessThanEqual(expr1, expr2) => Ok(if eval(*expr1)? <= eval(*expr2)? { 1.0 } else { 0.0 }),
+        GreaterThan(expr1, expr2) => Ok(if eval(*expr1)? > eval(*expr2)? { 1.0 } else { 0.0 }),
+        GreaterThanEqual(expr1, expr2) => {
+            Ok(if eval(*expr1)? >= eval(*expr2)? { 1.0 } else { 0.0 })
+        }
+        Constant(value) => Ok(value),
+        Function(name, expr) => apply_function(&name, eval(*expr)?),
+        Absolute(expr) => Ok(eval(*expr)?.abs()),
         And(expr1, expr2) => {
             let left = eval(*expr1)? as i64;
             let right = eval(*expr2)? as i64;
@@ -49,10 +144,220 @@ pub fn eval(expr: Node) -> Result<f64, Box<dyn error::Error>> {
     }
 }
 
+// A single instruction for the stack machine that `compile` lowers a `Node` tree into.
+// Binary operators assume the stack already holds `left` below `right`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instr {
+    Push(f64),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Mod,
+    FloorDiv,
+    Neg,
+    And,
+    Or,
+    Eq,
+    Neq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Abs,
+    Call(String),
+}
+
+// Lower an AST into a flat sequence of `Instr`s via a post-order walk: operands first,
+// then the operator that consumes them.
+pub fn compile(expr: &Node) -> Vec<Instr> {
+    use self::Node::*;
+    match expr {
+        Number(i) => vec![Instr::Push(*i)],
+        Add(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Add);
+            program
+        }
+        Subtract(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Sub);
+            program
+        }
+        Multiply(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Mul);
+            program
+        }
+        Divide(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Div);
+            program
+        }
+        Caret(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Pow);
+            program
+        }
+        Modulo(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Mod);
+            program
+        }
+        FloorDivide(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::FloorDiv);
+            program
+        }
+        Negative(expr) => {
+            let mut program = compile(expr);
+            program.push(Instr::Neg);
+            program
+        }
+        Equal(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Eq);
+            program
+        }
+        NotEqual(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Neq);
+            program
+        }
+        LessThan(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Lt);
+            program
+        }
+        LessThanEqual(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Lte);
+            program
+        }
+        GreaterThan(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Gt);
+            program
+        }
+        GreaterThanEqual(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Gte);
+            program
+        }
+        And(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::And);
+            program
+        }
+        Or(expr1, expr2) => {
+            let mut program = compile(expr1);
+            program.extend(compile(expr2));
+            program.push(Instr::Or);
+            program
+        }
+        Constant(value) => vec![Instr::Push(*value)],
+        Function(name, expr) => {
+            let mut program = compile(expr);
+            program.push(Instr::Call(name.clone()));
+            program
+        }
+        Absolute(expr) => {
+            let mut program = compile(expr);
+            program.push(Instr::Abs);
+            program
+        }
+    }
+}
+
+// Run a compiled program on a stack machine and return the final value.
+pub fn exec(program: &[Instr]) -> Result<f64, EvaluationError> {
+    let mut stack: Vec<f64> = Vec::new();
+
+    for instr in program {
+        match instr {
+            Instr::Push(n) => stack.push(*n),
+            Instr::Neg => {
+                let top = stack.pop().ok_or(EvaluationError::StackUnderflow)?;
+                stack.push(-top);
+            }
+            Instr::Abs => {
+                let top = stack.pop().ok_or(EvaluationError::StackUnderflow)?;
+                stack.push(top.abs());
+            }
+            Instr::Call(name) => {
+                let top = stack.pop().ok_or(EvaluationError::StackUnderflow)?;
+                stack.push(apply_function(name, top)?);
+            }
+            _ => {
+                let right = stack.pop().ok_or(EvaluationError::StackUnderflow)?;
+                let left = stack.pop().ok_or(EvaluationError::StackUnderflow)?;
+                let result = match instr {
+                    Instr::Add => left + right,
+                    Instr::Sub => left - right,
+                    Instr::Mul => left * right,
+                    Instr::Div => {
+                        if right == 0.0 {
+                            return Err(EvaluationError::DivisionByZero);
+                        }
+                        left / right
+                    }
+                    Instr::Pow => left.powf(right),
+                    Instr::Mod => {
+                        if right == 0.0 {
+                            return Err(EvaluationError::DivisionByZero);
+                        }
+                        left.rem_euclid(right)
+                    }
+                    Instr::FloorDiv => {
+                        if right == 0.0 {
+                            return Err(EvaluationError::DivisionByZero);
+                        }
+                        (left / right).floor()
+                    }
+                    Instr::And => ((left as i64) & (right as i64)) as f64,
+                    Instr::Or => ((left as i64) | (right as i64)) as f64,
+                    Instr::Eq => (left == right) as i64 as f64,
+                    Instr::Neq => (left != right) as i64 as f64,
+                    Instr::Lt => (left < right) as i64 as f64,
+                    Instr::Lte => (left <= right) as i64 as f64,
+                    Instr::Gt => (left > right) as i64 as f64,
+                    Instr::Gte => (left >= right) as i64 as f64,
+                    Instr::Push(_) | Instr::Neg | Instr::Abs | Instr::Call(_) => unreachable!(),
+                };
+                stack.push(result);
+            }
+        }
+    }
+
+    if stack.len() != 1 {
+        return Err(EvaluationError::MalformedProgram);
+    }
+    Ok(stack[0])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::parsemath::ast::Node::{Add, Multiply, Divide, Caret, And, Or, Number};
+    use crate::parsemath::ast::Node::{
+        Add, Multiply, Divide, Caret, And, Or, Number, Modulo, FloorDivide, LessThan,
+        LessThanEqual, GreaterThan, GreaterThanEqual, Equal, NotEqual, Constant, Function,
+        Absolute,
+    };
 
     #[test]
     fn test_exponentiation() {
@@ -86,4 +391,174 @@ mod tests {
         ); // 3 + (2 * 5) = 13
         assert_eq!(eval(expr).unwrap(), 13.0);
     }
+
+    #[test]
+    fn test_compile_exec_matches_eval() {
+        let expr = Add(
+            Box::new(Number(3.0)),
+            Box::new(Multiply(Box::new(Number(2.0)), Box::new(Number(5.0)))),
+        ); // 3 + (2 * 5) = 13
+        let program = compile(&expr);
+        assert_eq!(exec(&program).unwrap(), 13.0);
+    }
+
+    #[test]
+    fn test_compile_exec_division_order() {
+        let expr = Divide(Box::new(Number(10.0)), Box::new(Number(4.0)));
+        let program = compile(&expr);
+        assert_eq!(exec(&program).unwrap(), 2.5);
+    }
+
+    #[test]
+    fn test_compile_exec_division_by_zero() {
+        let expr = Divide(Box::new(Number(5.0)), Box::new(Number(0.0)));
+        let program = compile(&expr);
+        assert!(exec(&program).is_err());
+    }
+
+    #[test]
+    fn test_compile_exec_bitwise_and() {
+        let expr = And(Box::new(Number(6.0)), Box::new(Number(3.0))); // 6 & 3 = 2
+        let program = compile(&expr);
+        assert_eq!(exec(&program).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_modulo() {
+        let expr = Modulo(Box::new(Number(7.0)), Box::new(Number(3.0)));
+        assert_eq!(eval(expr).unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_floor_divide() {
+        let expr = FloorDivide(Box::new(Number(7.0)), Box::new(Number(2.0)));
+        assert_eq!(eval(expr).unwrap(), 3.0);
+    }
+
+    #[test]
+    fn test_floor_divide_by_zero() {
+        let expr = FloorDivide(Box::new(Number(7.0)), Box::new(Number(0.0)));
+        assert!(eval(expr).is_err());
+    }
+
+    #[test]
+    fn test_modulo_by_zero() {
+        let expr = Modulo(Box::new(Number(5.0)), Box::new(Number(0.0)));
+        let program = compile(&expr);
+        assert!(eval(expr).is_err());
+        assert!(exec(&program).is_err());
+    }
+
+    #[test]
+    fn test_less_than() {
+        assert_eq!(eval(LessThan(Box::new(Number(2.0)), Box::new(Number(3.0)))).unwrap(), 1.0);
+        assert_eq!(eval(LessThan(Box::new(Number(3.0)), Box::new(Number(2.0)))).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_less_than_equal() {
+        assert_eq!(
+            eval(LessThanEqual(Box::new(Number(2.0)), Box::new(Number(2.0)))).unwrap(),
+            1.0
+        );
+        assert_eq!(
+            eval(LessThanEqual(Box::new(Number(3.0)), Box::new(Number(2.0)))).unwrap(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_greater_than() {
+        assert_eq!(eval(GreaterThan(Box::new(Number(3.0)), Box::new(Number(2.0)))).unwrap(), 1.0);
+        assert_eq!(eval(GreaterThan(Box::new(Number(2.0)), Box::new(Number(3.0)))).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_greater_than_equal() {
+        assert_eq!(
+            eval(GreaterThanEqual(Box::new(Number(2.0)), Box::new(Number(2.0)))).unwrap(),
+            1.0
+        );
+        assert_eq!(
+            eval(GreaterThanEqual(Box::new(Number(2.0)), Box::new(Number(3.0)))).unwrap(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_equal() {
+        assert_eq!(eval(Equal(Box::new(Number(2.0)), Box::new(Number(2.0)))).unwrap(), 1.0);
+        assert_eq!(eval(Equal(Box::new(Number(2.0)), Box::new(Number(3.0)))).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_not_equal() {
+        assert_eq!(eval(NotEqual(Box::new(Number(2.0)), Box::new(Number(3.0)))).unwrap(), 1.0);
+        assert_eq!(eval(NotEqual(Box::new(Number(2.0)), Box::new(Number(2.0)))).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_compile_exec_comparisons() {
+        let cases = vec![
+            (LessThan(Box::new(Number(2.0)), Box::new(Number(3.0))), 1.0),
+            (LessThanEqual(Box::new(Number(3.0)), Box::new(Number(2.0))), 0.0),
+            (GreaterThan(Box::new(Number(3.0)), Box::new(Number(2.0))), 1.0),
+            (GreaterThanEqual(Box::new(Number(2.0)), Box::new(Number(3.0))), 0.0),
+            (Equal(Box::new(Number(2.0)), Box::new(Number(2.0))), 1.0),
+            (NotEqual(Box::new(Number(2.0)), Box::new(Number(2.0))), 0.0),
+        ];
+        for (expr, expected) in cases {
+            let program = compile(&expr);
+            assert_eq!(exec(&program).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_constant_resolution() {
+        assert_eq!(resolve_constant("pi"), Some(std::f64::consts::PI));
+        assert_eq!(resolve_constant("e"), Some(std::f64::consts::E));
+        assert_eq!(resolve_constant("not_a_constant"), None);
+    }
+
+    #[test]
+    fn test_function_sqrt() {
+        let expr = Function("sqrt".to_string(), Box::new(Number(4.0)));
+        assert_eq!(eval(expr).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_function_domain_error() {
+        let expr = Function("sqrt".to_string(), Box::new(Number(-1.0)));
+        assert!(eval(expr).is_err());
+    }
+
+    #[test]
+    fn test_function_unknown_name() {
+        let expr = Function("bogus".to_string(), Box::new(Number(1.0)));
+        assert!(eval(expr).is_err());
+    }
+
+    #[test]
+    fn test_function_abs_is_not_a_builtin() {
+        // `Absolute` is the one canonical path for `abs`; `Function("abs", ...)` is
+        // treated like any other unrecognized identifier.
+        let expr = Function("abs".to_string(), Box::new(Number(-5.0)));
+        assert!(eval(expr).is_err());
+    }
+
+    #[test]
+    fn test_absolute() {
+        let expr = Absolute(Box::new(Number(-5.0)));
+        assert_eq!(eval(expr).unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_compile_exec_function_and_constant() {
+        let expr = Function(
+            "sqrt".to_string(),
+            Box::new(Multiply(Box::new(Constant(4.0)), Box::new(Number(1.0)))),
+        );
+        let program = compile(&expr);
+        assert_eq!(exec(&program).unwrap(), 2.0);
+    }
 }
\ No newline at end of file
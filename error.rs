@@ -0,0 +1,55 @@
+/// Structured error types for the tokenizer and the evaluator, so callers can match on
+/// *why* an expression failed instead of parsing an opaque message.
+use std::error;
+use std::fmt;
+
+// Errors produced while scanning an expression into tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenizeError {
+    // `ch` was not recognized at byte/char offset `pos` in the source expression.
+    UnknownCharacter { ch: char, pos: usize },
+    // A numeric literal starting at `pos` parsed as valid digits but doesn't fit in
+    // the integer type backing it (e.g. a `0x`/`0b`/`0o` literal wider than `i64`).
+    Overflow { pos: usize },
+}
+
+impl fmt::Display for TokenizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TokenizeError::UnknownCharacter { ch, pos } => {
+                write!(f, "unknown character '{}' at position {}", ch, pos)
+            }
+            TokenizeError::Overflow { pos } => {
+                write!(f, "numeric literal at position {} overflows", pos)
+            }
+        }
+    }
+}
+
+impl error::Error for TokenizeError {}
+
+// Errors produced while evaluating an AST (tree-walking or the bytecode VM).
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvaluationError {
+    DivisionByZero,
+    DomainError(String),
+    StackUnderflow,
+    MalformedProgram,
+    UnknownFunction(String),
+}
+
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvaluationError::DivisionByZero => write!(f, "division by zero"),
+            EvaluationError::DomainError(msg) => write!(f, "domain error: {}", msg),
+            EvaluationError::StackUnderflow => write!(f, "stack underflow"),
+            EvaluationError::MalformedProgram => {
+                write!(f, "malformed program: expected exactly one value left on the stack")
+            }
+            EvaluationError::UnknownFunction(name) => write!(f, "unknown function: {}", name),
+        }
+    }
+}
+
+impl error::Error for EvaluationError {}
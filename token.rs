@@ -0,0 +1,29 @@
+/// The set of lexical tokens that `Tokenizer` can produce from an arithmetic expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Num(f64),
+    Ident(String),
+
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Caret,
+    Modulo,
+    FloorDivide,
+
+    And,
+    Or,
+
+    Equal,
+    NotEqual,
+    LessThan,
+    LessThanEqual,
+    GreaterThan,
+    GreaterThanEqual,
+
+    LeftParen,
+    RightParen,
+
+    EOF,
+}
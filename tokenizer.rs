@@ -6,11 +6,14 @@ use std::iter::Peekable;
 use std::str::Chars;
 
 // Internal modules
+use super::error::TokenizeError;
 use super::token::Token;
 
-// Tokenizer struct contains a Peekable iterator on the arithmetic expression
+// Tokenizer struct contains a Peekable iterator on the arithmetic expression, plus the
+// char offset of the next character to be consumed (used to report error positions).
 pub struct Tokenizer<'a> {
     expr: Peekable<Chars<'a>>,
+    pos: usize,
 }
 
 // Constructs a new instance of Tokenizer
@@ -18,89 +21,232 @@ impl<'a> Tokenizer<'a> {
     pub fn new(new_expr: &'a str) -> Self {
         Tokenizer {
             expr: new_expr.chars().peekable(),
+            pos: 0,
         }
     }
 
-    // Helper function to parse a number (integer or floating point)
-    fn parse_number(&mut self, first_digit: char) -> Option<Token> {
+    // Consume and return the next character, advancing `pos`.
+    fn advance(&mut self) -> Option<char> {
+        let next = self.expr.next();
+        if next.is_some() {
+            self.pos += 1;
+        }
+        next
+    }
+
+    // Helper function to parse a number (integer or floating point), including
+    // `0x`/`0b`/`0o`-prefixed integer literals and `_` digit separators. `start` is the
+    // position of `first_digit`, used to report where a malformed literal began.
+    fn parse_number(&mut self, first_digit: char, start: usize) -> Result<Token, TokenizeError> {
+        if first_digit == '0' {
+            let radix = match self.expr.peek() {
+                Some('x') | Some('X') => Some(16),
+                Some('b') | Some('B') => Some(2),
+                Some('o') | Some('O') => Some(8),
+                _ => None,
+            };
+            if let Some(radix) = radix {
+                self.advance(); // Consume the 'x'/'b'/'o' prefix character
+                return self.parse_radix_number(radix, start);
+            }
+        }
+
         let mut num_str = first_digit.to_string();
+        let mut dot_seen = false;
+        // The first extra `.` we see (e.g. the second one in "1.2.3"), so a parse
+        // failure can point at the character that actually broke the literal.
+        let mut malformed_at: Option<(char, usize)> = None;
 
         while let Some(&next) = self.expr.peek() {
-            if next.is_ascii_digit() || next == '.' {
-                num_str.push(self.expr.next().unwrap());
+            if next == '.' {
+                if dot_seen && malformed_at.is_none() {
+                    malformed_at = Some((next, self.pos));
+                }
+                dot_seen = true;
+                num_str.push(self.advance().unwrap());
+            } else if next.is_ascii_digit() || next == '_' {
+                num_str.push(self.advance().unwrap());
             } else {
                 break;
             }
         }
+        num_str.retain(|c| c != '_');
 
         match num_str.parse::<f64>() {
-            Ok(value) => Some(Token::Num(value)),
-            Err(_) => None, // If parsing fails, return None
+            Ok(value) => Ok(Token::Num(value)),
+            // If parsing still fails, report the character that actually made the
+            // literal malformed rather than blaming the leading digit.
+            Err(_) => {
+                let (ch, pos) = malformed_at.unwrap_or((first_digit, start));
+                Err(TokenizeError::UnknownCharacter { ch, pos })
+            }
         }
     }
-}
 
-// Implement Iterator trait for Tokenizer struct.
-// With this, we can use `next()` method on tokenizer to retrieve the next token from an arithmetic expression.
+    // Helper function to parse the digits of a `0x`/`0b`/`0o`-prefixed integer literal.
+    // A `.` is not valid in these literals, so its presence is a parse error.
+    fn parse_radix_number(&mut self, radix: u32, start: usize) -> Result<Token, TokenizeError> {
+        let mut digits = String::new();
 
-impl<'a> Iterator for Tokenizer<'a> {
-    type Item = Token;
+        while let Some(&next) = self.expr.peek() {
+            if next == '_' {
+                self.advance();
+            } else if next == '.' {
+                // Non-decimal literals cannot contain a fractional part
+                return Err(TokenizeError::UnknownCharacter { ch: next, pos: self.pos });
+            } else if next.is_digit(radix) {
+                digits.push(self.advance().unwrap());
+            } else {
+                break;
+            }
+        }
 
-    fn next(&mut self) -> Option<Token> {
+        if digits.is_empty() {
+            // e.g. a bare "0x" with no digits after the prefix
+            return Err(TokenizeError::UnknownCharacter { ch: '0', pos: start });
+        }
+
+        match i64::from_str_radix(&digits, radix) {
+            Ok(value) => Ok(Token::Num(value as f64)),
+            // `digits` is non-empty and was filtered to valid radix digits above, so the
+            // only way `from_str_radix` can still fail is if the literal overflows `i64`.
+            Err(_) => Err(TokenizeError::Overflow { pos: start }),
+        }
+    }
+
+    // Helper function to parse an identifier: a run of alphanumeric characters and
+    // underscores starting with a letter or underscore. Used for named constants
+    // (`pi`, `e`) and function calls (`sqrt(...)`).
+    fn parse_identifier(&mut self, first_char: char) -> Option<Token> {
+        let mut ident = first_char.to_string();
+
+        while let Some(&next) = self.expr.peek() {
+            if next.is_alphanumeric() || next == '_' {
+                ident.push(self.advance().unwrap());
+            } else {
+                break;
+            }
+        }
+
+        Some(Token::Ident(ident))
+    }
+
+    // Scan and return the next token, distinguishing end of input (`Ok(Token::EOF)`)
+    // from a malformed expression (`Err(TokenizeError)`), which the plain `Iterator`
+    // impl can only report as `None`, dropping the error's character and position.
+    pub fn try_next(&mut self) -> Result<Token, TokenizeError> {
         while let Some(&c) = self.expr.peek() {
+            let start = self.pos;
             match c {
                 '0'..='9' => {
-                    self.expr.next(); // Consume the character
-                    return self.parse_number(c);
+                    self.advance();
+                    return self.parse_number(c, start);
+                }
+                c if c.is_alphabetic() || c == '_' => {
+                    self.advance();
+                    return self
+                        .parse_identifier(c)
+                        .ok_or(TokenizeError::UnknownCharacter { ch: c, pos: start });
                 }
                 '+' => {
-                    self.expr.next();
-                    return Some(Token::Add);
+                    self.advance();
+                    return Ok(Token::Add);
                 }
                 '-' => {
-                    self.expr.next();
-                    return Some(Token::Subtract);
+                    self.advance();
+                    return Ok(Token::Subtract);
                 }
                 '*' => {
-                    self.expr.next();
-                    return Some(Token::Multiply);
+                    self.advance();
+                    return Ok(Token::Multiply);
                 }
                 '/' => {
-                    self.expr.next();
-                    return Some(Token::Divide);
+                    self.advance();
+                    if self.expr.peek() == Some(&'/') {
+                        self.advance();
+                        return Ok(Token::FloorDivide);
+                    }
+                    return Ok(Token::Divide);
                 }
                 '^' => {
-                    self.expr.next();
-                    return Some(Token::Caret);
+                    self.advance();
+                    return Ok(Token::Caret);
+                }
+                '%' => {
+                    self.advance();
+                    return Ok(Token::Modulo);
+                }
+                '=' => {
+                    self.advance();
+                    if self.expr.peek() == Some(&'=') {
+                        self.advance();
+                        return Ok(Token::Equal);
+                    }
+                    return Err(TokenizeError::UnknownCharacter { ch: c, pos: start });
+                }
+                '!' => {
+                    self.advance();
+                    if self.expr.peek() == Some(&'=') {
+                        self.advance();
+                        return Ok(Token::NotEqual);
+                    }
+                    return Err(TokenizeError::UnknownCharacter { ch: c, pos: start });
+                }
+                '<' => {
+                    self.advance();
+                    if self.expr.peek() == Some(&'=') {
+                        self.advance();
+                        return Ok(Token::LessThanEqual);
+                    }
+                    return Ok(Token::LessThan);
+                }
+                '>' => {
+                    self.advance();
+                    if self.expr.peek() == Some(&'=') {
+                        self.advance();
+                        return Ok(Token::GreaterThanEqual);
+                    }
+                    return Ok(Token::GreaterThan);
                 }
                 '&' => {
-                    self.expr.next();
-                    return Some(Token::And);
+                    self.advance();
+                    return Ok(Token::And);
                 }
                 '|' => {
-                    self.expr.next();
-                    return Some(Token::Or);
+                    self.advance();
+                    return Ok(Token::Or);
                 }
                 '(' => {
-                    self.expr.next();
-                    return Some(Token::LeftParen);
+                    self.advance();
+                    return Ok(Token::LeftParen);
                 }
                 ')' => {
-                    self.expr.next();
-                    return Some(Token::RightParen);
+                    self.advance();
+                    return Ok(Token::RightParen);
                 }
                 ' ' | '\t' | '\n' => {
                     // Skip whitespace
-                    self.expr.next();
+                    self.advance();
                 }
                 _ => {
-                    // If an unknown character is found, return None
-                    self.expr.next();
-                    return None;
+                    self.advance();
+                    return Err(TokenizeError::UnknownCharacter { ch: c, pos: start });
                 }
             }
         }
-        Some(Token::EOF)
+        Ok(Token::EOF)
+    }
+}
+
+// Implement Iterator trait for Tokenizer struct.
+// With this, we can use `next()` method on tokenizer to retrieve the next token from an
+// arithmetic expression. End of input yields `Some(Token::EOF)`; bad input yields `None`,
+// without the error's details; call `try_next` directly to get the `TokenizeError`.
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        self.try_next().ok()
     }
 }
 
@@ -156,4 +302,118 @@ mod tests {
         assert_eq!(tokenizer.next().unwrap(), Token::Num(2.0));
         assert_eq!(tokenizer.next().unwrap(), Token::RightParen);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_tokenize_hex_literal() {
+        let mut tokenizer = Tokenizer::new("0xFF");
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(255.0));
+    }
+
+    #[test]
+    fn test_tokenize_binary_literal() {
+        let mut tokenizer = Tokenizer::new("0b101");
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(5.0));
+    }
+
+    #[test]
+    fn test_tokenize_octal_literal() {
+        let mut tokenizer = Tokenizer::new("0o17");
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(15.0));
+    }
+
+    #[test]
+    fn test_tokenize_digit_separators() {
+        let mut tokenizer = Tokenizer::new("1_000_000");
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(1_000_000.0));
+
+        let mut tokenizer = Tokenizer::new("0xFF_FF");
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(65535.0));
+    }
+
+    #[test]
+    fn test_tokenize_hex_literal_rejects_dot() {
+        let mut tokenizer = Tokenizer::new("0x1.5");
+        assert_eq!(tokenizer.next(), None);
+    }
+
+    #[test]
+    fn test_tokenize_modulo_and_floor_divide() {
+        let mut tokenizer = Tokenizer::new("7%2//2");
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(7.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::Modulo);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(2.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::FloorDivide);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(2.0));
+    }
+
+    #[test]
+    fn test_tokenize_comparison_operators() {
+        let mut tokenizer = Tokenizer::new("1==2!=3<=4>=5<6>7");
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(1.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::Equal);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(2.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::NotEqual);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(3.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::LessThanEqual);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(4.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::GreaterThanEqual);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(5.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::LessThan);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(6.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::GreaterThan);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(7.0));
+    }
+
+    #[test]
+    fn test_tokenize_identifier() {
+        let mut tokenizer = Tokenizer::new("sqrt(2)*pi");
+        assert_eq!(tokenizer.next().unwrap(), Token::Ident("sqrt".to_string()));
+        assert_eq!(tokenizer.next().unwrap(), Token::LeftParen);
+        assert_eq!(tokenizer.next().unwrap(), Token::Num(2.0));
+        assert_eq!(tokenizer.next().unwrap(), Token::RightParen);
+        assert_eq!(tokenizer.next().unwrap(), Token::Multiply);
+        assert_eq!(tokenizer.next().unwrap(), Token::Ident("pi".to_string()));
+    }
+
+    #[test]
+    fn test_tokenize_identifier_with_underscore_and_digits() {
+        let mut tokenizer = Tokenizer::new("_foo_2 bar3");
+        assert_eq!(tokenizer.next().unwrap(), Token::Ident("_foo_2".to_string()));
+        assert_eq!(tokenizer.next().unwrap(), Token::Ident("bar3".to_string()));
+    }
+
+    #[test]
+    fn test_try_next_reports_unknown_character_position() {
+        let mut tokenizer = Tokenizer::new("1+@");
+        assert_eq!(tokenizer.try_next().unwrap(), Token::Num(1.0));
+        assert_eq!(tokenizer.try_next().unwrap(), Token::Add);
+        assert_eq!(
+            tokenizer.try_next().unwrap_err(),
+            TokenizeError::UnknownCharacter { ch: '@', pos: 2 }
+        );
+    }
+
+    #[test]
+    fn test_try_next_reports_malformed_decimal_literal_position() {
+        let mut tokenizer = Tokenizer::new("1.2.3");
+        assert_eq!(
+            tokenizer.try_next().unwrap_err(),
+            TokenizeError::UnknownCharacter { ch: '.', pos: 3 }
+        );
+    }
+
+    #[test]
+    fn test_try_next_distinguishes_eof_from_error() {
+        let mut tokenizer = Tokenizer::new("");
+        assert_eq!(tokenizer.try_next().unwrap(), Token::EOF);
+    }
+
+    #[test]
+    fn test_try_next_reports_overflow_for_oversized_hex_literal() {
+        let mut tokenizer = Tokenizer::new("0xFFFFFFFFFFFFFFFFFFFF");
+        assert_eq!(
+            tokenizer.try_next().unwrap_err(),
+            TokenizeError::Overflow { pos: 0 }
+        );
+    }
+}